@@ -1,27 +1,88 @@
 #[doc = include_str!("../README.md")]
 
-#[cfg(any(feature = "strictly_heterogeneous", feature = "order_dependent"))]
+#[cfg(any(
+    feature = "strictly_heterogeneous",
+    feature = "order_dependent",
+    feature = "try_from_tuple"
+))]
 use {
     proc_macro::TokenStream,
     quote::quote,
     syn::parse_macro_input,
 };
 
-#[cfg(feature="order_dependent")]
-use {
-    quote::ToTokens,
-    proc_macro2::TokenStream as TokenStream2,
-};
+#[cfg(any(feature = "strictly_heterogeneous", feature = "order_dependent"))]
+use proc_macro2::TokenStream as TokenStream2;
+
+#[cfg(any(
+    feature = "strictly_heterogeneous",
+    feature = "order_dependent",
+    feature = "try_from_tuple"
+))]
+use syn::{Data, DeriveInput, Error};
 
 #[cfg(feature = "strictly_heterogeneous")]
 mod strictly_heterogeneous;
 
 #[cfg(feature = "strictly_heterogeneous")]
-use {
-    syn::{Data, DeriveInput, Error},
-    strictly_heterogeneous::{impl_from_tuple, permute, verify_unique_field_types}
+use strictly_heterogeneous::{
+    impl_from_tuple, is_defaulted, permute, tuple_signature, verify_unique_field_types,
 };
 
+#[cfg(feature = "try_from_tuple")]
+mod try_from_tuple;
+
+#[cfg(feature = "try_from_tuple")]
+use try_from_tuple::impl_try_from_tuple;
+
+/// Checks that no two `(field-type tuple, variant name)` entries share the same tuple,
+/// which would mean two `enum` variants generate overlapping, coherence-conflicting
+/// `From<(...)>` impls for the same tuple type. Errors for every such pair are combined
+/// into one spanned [`Error`], pointing at both variants involved.
+///
+/// When `into_mode` is set, the converting `impl<A0,..> From<(A0,..)> for ..` that
+/// `#[from_tuple(into)]` generates is generic over the tuple's arity, not its concrete
+/// field types, so two variants of the *same arity* conflict regardless of whether
+/// their field types actually differ; in that case any two same-arity variants are
+/// also rejected.
+#[cfg(any(feature = "strictly_heterogeneous", feature = "order_dependent"))]
+fn check_variant_signatures_unique(
+    signatures: &[(Vec<syn::Type>, &syn::Ident)],
+    into_mode: bool,
+) -> syn::Result<()> {
+    let mut error: Option<Error> = None;
+
+    for (i, (signature, variant)) in signatures.iter().enumerate() {
+        for (other_signature, other_variant) in &signatures[..i] {
+            let conflicts = signature == other_signature
+                || (into_mode && signature.len() == other_signature.len());
+            if conflicts {
+                let mut new_error = Error::new_spanned(
+                    variant,
+                    format!(
+                        "variant `{}` and variant `{}` would both generate a `From` impl for the same tuple of field types",
+                        variant, other_variant
+                    ),
+                );
+                new_error.combine(Error::new_spanned(
+                    other_variant,
+                    format!("variant `{}` conflicts with variant `{}` here", other_variant, variant),
+                ));
+
+                match &mut error {
+                    None => error = Some(new_error),
+                    Some(error) => error.combine(new_error),
+                }
+            }
+        }
+    }
+
+    match error {
+        None => Ok(()),
+        Some(error) => Err(error),
+    }
+}
+
 /// Derives `n!` implementations of [`core::convert::From<...>`][core::convert::From] on `struct`s that have 
 /// unique field types `T1,T2,...,Tn`.
 /// 
@@ -67,6 +128,33 @@ use {
 /// }
 /// ```
 ///
+/// ## Tuple structs
+///
+/// Tuple structs (and single-field newtypes in particular) are supported the
+/// same way, reassembling fields positionally instead of by name. Note that a
+/// single field still derives a `From` for a 1-tuple, not a bare value.
+///
+/// ```
+/// use from_tuple::FromStrictlyHeterogeneousTuple;
+///
+/// #[derive(FromStrictlyHeterogeneousTuple)]
+/// struct Meters(f64);
+///
+/// #[derive(FromStrictlyHeterogeneousTuple)]
+/// struct Pair(i32, bool);
+///
+/// let m: Meters = (12.5,).into();
+/// assert_eq!(m.0, 12.5);
+///
+/// let p1: Pair = (1, true).into();
+/// assert_eq!(p1.0, 1);
+/// assert!(p1.1);
+///
+/// let p2: Pair = (false, 2).into();
+/// assert_eq!(p2.0, 2);
+/// assert!(!p2.1);
+/// ```
+///
 /// ## Structs with non-unique field types
 ///
 /// Structs that have non-unique field types will fail to compile.  This is based
@@ -101,29 +189,125 @@ use {
 ///
 /// Requiring unique types may also be *surprising* behaviour, but is able to
 /// be caught at compile time easily.
-/// 
-/// Also, at the moment of writing, only [`OrderDependentFromTuple`] also derives generic trait implementations
-/// with the caveat that bounds must be only in the where clause.
+///
+/// Both derives thread the struct's generics and where-clause through into the
+/// generated `impl`. Note that the uniqueness check on field types is purely
+/// structural: two fields sharing a generic parameter `T`, e.g. `a: T, b: T`,
+/// are still (correctly) rejected as non-unique even though `T` isn't resolved
+/// until monomorphization.
+///
+/// ## Skipping fields with `#[from_tuple(default)]`
+///
+/// A field annotated `#[from_tuple(default)]` is left out of the generated
+/// tuple entirely and filled in with `Default::default()` instead, so the
+/// struct can have bookkeeping fields that aren't present in the input
+/// tuple. Only the non-defaulted fields need to have unique types, and only
+/// they are permuted.
+///
+/// ## Enums
+///
+/// Deriving on an `enum` generates every permutation's `impl` per variant,
+/// constructing that variant. Because two variants with the same
+/// (non-defaulted) field types would otherwise produce overlapping impls for
+/// the same tuple, that case is rejected with a combined error spanning both
+/// variants instead of being silently accepted.
+///
+/// ```
+/// use from_tuple::FromStrictlyHeterogeneousTuple;
+///
+/// #[derive(FromStrictlyHeterogeneousTuple)]
+/// enum Shape {
+///     Circle(f64),
+///     Rect(f64, bool),
+/// }
+///
+/// let circle: Shape = (1.5,).into();
+/// assert!(matches!(circle, Shape::Circle(r) if r == 1.5));
+///
+/// let rect: Shape = (2.5, true).into();
+/// assert!(matches!(rect, Shape::Rect(w, big) if w == 2.5 && big));
+///
+/// // Field order within a variant's tuple is permuted just like for structs.
+/// let rect2: Shape = (true, 2.5).into();
+/// assert!(matches!(rect2, Shape::Rect(w, big) if w == 2.5 && big));
+/// ```
 #[cfg(feature = "strictly_heterogeneous")]
-#[proc_macro_derive(FromStrictlyHeterogeneousTuple)]
+#[proc_macro_derive(FromStrictlyHeterogeneousTuple, attributes(from_tuple))]
 pub fn from_strictly_heterogeneous_tuple(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    if let Data::Struct(data) = &input.data {
-        if let Err(error) = verify_unique_field_types(&data.fields) {
-            return error.to_compile_error().into();
+    strictly_heterogeneous_impls(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Builds every `impl_from_tuple` permutation for a `struct`, or for each variant of
+/// an `enum` in turn, returning a combined diagnostic instead of panicking or bailing
+/// out on the first problem.
+#[cfg(feature = "strictly_heterogeneous")]
+fn strictly_heterogeneous_impls(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut impls = Vec::new();
+
+    match &input.data {
+        Data::Struct(data) => {
+            let (defaulted, tuple_fields): (Vec<_>, Vec<_>) = data
+                .fields
+                .iter()
+                .enumerate()
+                .partition(|&(_, field)| is_defaulted(field));
+
+            verify_unique_field_types(tuple_fields.iter().map(|&(_, f)| f))?;
+
+            permute(&tuple_fields, |fields| {
+                impls.push(impl_from_tuple(fields, &defaulted, input, &quote! { Self }))
+            });
         }
+        Data::Enum(data) => {
+            let mut signatures = Vec::new();
+            let mut error: Option<Error> = None;
 
-        let mut impls = Vec::new();
-        permute(&data.fields, |fields| {
-            impls.push(impl_from_tuple(fields, &input))
-        });
+            for variant in &data.variants {
+                let (defaulted, tuple_fields): (Vec<_>, Vec<_>) = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .partition(|&(_, field)| is_defaulted(field));
 
-        quote! { #(#impls)* }
-    } else {
-        Error::new_spanned(input, "FromStrictlyHeterogeneousTuple currently only supports Struct").to_compile_error()
+                if let Err(new_error) = verify_unique_field_types(tuple_fields.iter().map(|&(_, f)| f)) {
+                    match &mut error {
+                        None => error = Some(new_error),
+                        Some(error) => error.combine(new_error),
+                    }
+                    continue;
+                }
+
+                let variant_ident = &variant.ident;
+                let target = quote! { Self::#variant_ident };
+                permute(&tuple_fields, |fields| {
+                    signatures.push((tuple_signature(fields), variant_ident));
+                    impls.push(impl_from_tuple(fields, &defaulted, input, &target));
+                });
+            }
+
+            if let Err(new_error) = check_variant_signatures_unique(&signatures, false) {
+                match &mut error {
+                    None => error = Some(new_error),
+                    Some(error) => error.combine(new_error),
+                }
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FromStrictlyHeterogeneousTuple currently only supports Struct and Enum",
+            ));
+        }
     }
-    .into()
+
+    Ok(quote! { #(#impls)* })
 }
 
 /// Derives implementation of [`core::convert::From<(T1,T2,...,Tn)>`][core::convert::From] on `struct`s
@@ -152,51 +336,395 @@ pub fn from_strictly_heterogeneous_tuple(input: TokenStream) -> TokenStream {
 ///
 /// Requiring unique types may also be *surprising* behaviour, but is able to
 /// be caught at compile time easily.
-/// 
-/// Also, at the moment of writing, only [`OrderDependentFromTuple`] also derives generic trait implementations
-/// with the caveat that bounds must be only in the where clause
+///
+/// Both derives thread the struct's generics and where-clause through into the
+/// generated `impl`.
+///
+/// ```
+/// use std::fmt::Debug;
+/// use from_tuple::OrderDependentFromTuple;
+///
+/// #[derive(OrderDependentFromTuple)]
+/// struct Bounded<T: Debug> {
+///     val: T,
+/// }
+///
+/// let bounded: Bounded<i32> = (42,).into();
+/// assert_eq!(bounded.val, 42);
+/// ```
+///
+/// ## Converting tuples with `#[from_tuple(into)]`
+///
+/// Annotating the struct with `#[from_tuple(into)]` swaps the exact-type `impl` for a
+/// generic `impl<A0,A1,..> From<(A0,A1,..)> for #struct` with one `Ai: Into<Ti>`
+/// bound per field, so e.g. `let h: Hello = ("world", -1i32, 42usize).into();` works
+/// even though `"world"` is a `&str` and `Hello::message` is a `String`; the exact-type
+/// impl isn't generated alongside it, since `impl<T> From<T> for T` already makes the
+/// converting impl cover that case, and emitting both would conflict. This mode is only
+/// available here, not on [`FromStrictlyHeterogeneousTuple`], since generating one such
+/// generic impl per permutation would produce overlapping, coherence-conflicting impls.
+///
+/// ```
+/// use from_tuple::OrderDependentFromTuple;
+///
+/// #[derive(OrderDependentFromTuple)]
+/// #[from_tuple(into)]
+/// struct Hello {
+///     message: String,
+///     time: i32,
+///     counter: usize,
+/// }
+///
+/// let h: Hello = ("world", -1i32, 42usize).into();
+/// assert_eq!(h.time, -1);
+/// assert_eq!(h.counter, 42);
+/// assert_eq!(&h.message, "world");
+/// ```
+///
+/// ## Skipping fields with `#[from_tuple(default)]`
+///
+/// Just like on [`FromStrictlyHeterogeneousTuple`], a field annotated
+/// `#[from_tuple(default)]` is left out of the tuple and filled in with
+/// `Default::default()` instead, in whichever `impl` is generated: the
+/// exact-type one, or the `#[from_tuple(into)]` one.
+///
+/// Note that when defaulting leaves exactly one non-defaulted field, the
+/// generated `impl` is still for a 1-tuple, not a bare value.
+///
+/// ```
+/// use from_tuple::OrderDependentFromTuple;
+///
+/// #[derive(OrderDependentFromTuple)]
+/// struct WithDefault {
+///     val: i32,
+///     #[from_tuple(default)]
+///     extra: bool,
+/// }
+///
+/// let w: WithDefault = (1,).into();
+/// assert_eq!(w.val, 1);
+/// assert!(!w.extra);
+/// ```
+///
+/// ## Enums
+///
+/// Deriving on an `enum` generates one `impl` per variant, constructing that variant.
+/// Two variants with the same (non-defaulted) field types would otherwise produce
+/// overlapping impls for the same tuple, so that case is rejected with a combined
+/// error spanning both variants instead of being silently accepted. With
+/// `#[from_tuple(into)]`, the generated impl is generic over arity rather than
+/// concrete field types, so two variants of the same arity conflict and are
+/// rejected the same way, even if their field types differ.
+///
+/// ```
+/// use from_tuple::OrderDependentFromTuple;
+///
+/// #[derive(OrderDependentFromTuple)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Rect { width: f64, height: f64 },
+/// }
+///
+/// let circle = Shape::from((1.5,));
+/// assert!(matches!(circle, Shape::Circle { radius } if radius == 1.5));
+///
+/// let rect = Shape::from((2.0, 3.0));
+/// assert!(matches!(rect, Shape::Rect { width, height } if width == 2.0 && height == 3.0));
+/// ```
+///
+/// Combining `#[from_tuple(into)]` with two variants of the same arity is rejected
+/// even though their field types differ, since the generated impls would otherwise
+/// overlap for any `(A0,)` that is `Into` both `f64` and `bool`.
+///
+/// ```compile_fail
+/// use from_tuple::OrderDependentFromTuple;
+///
+/// #[derive(OrderDependentFromTuple)]
+/// #[from_tuple(into)]
+/// enum Shape {
+///     Circle(f64),
+///     Square(bool),
+/// }
+/// ```
 #[cfg(feature="order_dependent")]
-#[proc_macro_derive(OrderDependentFromTuple)]
+#[proc_macro_derive(OrderDependentFromTuple, attributes(from_tuple))]
 pub fn derive_from(item: TokenStream) -> TokenStream {
-    use syn::{ItemStruct, Fields, token::Comma};
+    let input = parse_macro_input!(item as DeriveInput);
+
+    order_dependent_impls(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Builds the exact-type (or, with `#[from_tuple(into)]`, instead the converting) `impl`s for
+/// a `struct`, or for each variant of an `enum` in turn, returning a combined
+/// diagnostic instead of panicking or bailing out on the first problem.
+#[cfg(feature="order_dependent")]
+fn order_dependent_impls(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let into_mode = from_tuple_into_attr(&input.attrs);
+    let ident = &input.ident;
+    let generics = &input.generics;
 
-    let item_struct = parse_macro_input!(item as ItemStruct);
-    let fields = match item_struct.fields {
-        Fields::Named(fields) => fields,
-        _ => panic!("expected named fields"),
+    match &input.data {
+        Data::Struct(data) => {
+            let (ts, _) =
+                order_dependent_impl_for_fields(&data.fields, &quote! { Self }, ident, generics, into_mode);
+            Ok(ts)
+        }
+        Data::Enum(data) => {
+            let mut ts = TokenStream2::new();
+            let mut signatures = Vec::new();
+
+            for variant in &data.variants {
+                let variant_ident = &variant.ident;
+                let target = quote! { Self::#variant_ident };
+                let (variant_ts, tuple_tys) =
+                    order_dependent_impl_for_fields(&variant.fields, &target, ident, generics, into_mode);
+
+                signatures.push((tuple_tys, variant_ident));
+                ts.extend(variant_ts);
+            }
+
+            check_variant_signatures_unique(&signatures, into_mode)?;
+
+            Ok(ts)
+        }
+        _ => Err(Error::new_spanned(
+            ident,
+            "OrderDependentFromTuple currently only supports Struct and Enum",
+        )),
+    }
+}
+
+/// Builds the exact-type (or, with `into_mode`, instead the converting) `impl From<...>` for a
+/// single `struct` or `enum` variant's `fields`, constructing `target` (`Self` for a
+/// `struct`, `Self::Variant` for an `enum` variant). Also returns the non-defaulted
+/// field types, in order, so callers can detect two `enum` variants generating
+/// overlapping impls for the same tuple type.
+#[cfg(feature="order_dependent")]
+fn order_dependent_impl_for_fields(
+    fields: &syn::Fields,
+    target: &TokenStream2,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    into_mode: bool,
+) -> (TokenStream2, Vec<syn::Type>) {
+    use proc_macro2::{Ident, Span};
+
+    let is_named = matches!(fields, syn::Fields::Named(_));
+    // One entry per field, in declared order, tagged with whether it's
+    // `#[from_tuple(default)]`.
+    let field_specs = fields
+        .iter()
+        .map(|f| (f.ident.clone(), f.ty.clone(), from_tuple_default_attr(&f.attrs)))
+        .collect::<Vec<_>>();
+
+    let tuple_tys = field_specs
+        .iter()
+        .filter(|(_, _, defaulted)| !defaulted)
+        .map(|(_, ty, _)| ty.clone())
+        .collect::<Vec<_>>();
+    let dvars = (0..tuple_tys.len())
+        .map(|i| Ident::new(&format!("d{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    // With exactly one field, `(#(#tuple_tys),*)` would expand to the parenthesized
+    // type `(T0)` rather than the 1-tuple `(T0,)` — keep the trailing comma so
+    // `tuple_ty_ts`/`pattern_ts` are always real tuples.
+    let tuple_ty_ts = quote! { (#(#tuple_tys,)*) };
+    let pattern_ts = quote! { (#(#dvars,)*) };
+
+    // Builds `#target { .. }`/`#target(..)`/`#target` for the given per-tuple-field expression.
+    let build_construct = |field_expr: &dyn Fn(&Ident) -> TokenStream2| -> TokenStream2 {
+        let mut dvars_iter = dvars.iter();
+        if field_specs.is_empty() {
+            quote! { #target }
+        } else if is_named {
+            let assigns = field_specs.iter().map(|(ident, _, defaulted)| {
+                let ident = ident.as_ref().expect("named field always has an ident");
+                if *defaulted {
+                    quote! { #ident: ::core::default::Default::default() }
+                } else {
+                    let dvar = dvars_iter.next().expect("one dvar per non-defaulted field");
+                    let expr = field_expr(dvar);
+                    quote! { #ident: #expr }
+                }
+            });
+            quote! { #target { #(#assigns),* } }
+        } else {
+            let exprs = field_specs.iter().map(|(_, _, defaulted)| {
+                if *defaulted {
+                    quote! { ::core::default::Default::default() }
+                } else {
+                    let dvar = dvars_iter.next().expect("one dvar per non-defaulted field");
+                    field_expr(dvar)
+                }
+            });
+            quote! { #target(#(#exprs),*) }
+        }
     };
 
-    let struct_name = item_struct.ident;
-    let where_clause = item_struct.generics.where_clause.as_ref();
-    let generics = &item_struct.generics;
-    let fields_iter = fields.named.iter();
-    let fields_tys_ts = fields_iter.clone()
-        .map(|f| f.ty.clone())
-        .fold(TokenStream2::new(), |mut ts,ty| {
-            let ty_ts: TokenStream2 = ty.into_token_stream();
-            ts.extend(ty_ts);
-            let comma_ts = Comma::default().into_token_stream();
-            ts.extend(comma_ts);
-            ts
-        });
-    let fields_names_ts = fields_iter
-        .filter_map(|f| f.ident.clone())
-        .fold(TokenStream2::new(), |mut ts,ident| {
-            let ident_ts: TokenStream2 = ident.into_token_stream();
-            ts.extend(ident_ts);
-            let comma_ts = Comma::default().into_token_stream();
-            ts.extend(comma_ts);
-            ts
-        });
-
-
-    let ts: TokenStream2 = quote! {
-        impl #generics ::core::convert::From<(#fields_tys_ts)> for #struct_name #generics
-        #where_clause {
-            fn from((#fields_names_ts): (#fields_tys_ts)) -> Self {
-                Self { #fields_names_ts }
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    // `#[from_tuple(into)]` swaps in a converting generic impl instead of the exact-type
+    // one below: `impl<T> From<T> for T` means the converting impl already covers the
+    // exact-type case, so emitting both would give rustc two overlapping, coherence-
+    // conflicting `From` impls for the same concrete tuple type.
+    let ts: TokenStream2 = if into_mode {
+        let into_params = (0..tuple_tys.len())
+            .map(|i| Ident::new(&format!("__IntoFromTuple{}", i), Span::call_site()))
+            .collect::<Vec<_>>();
+        let into_construct_ts = build_construct(&|dvar| quote! { #dvar.into() });
+        let into_tuple_ty_ts = quote! { (#(#into_params,)*) };
+
+        // Clone the struct's own generics and extend them with one fresh type
+        // parameter per tuple field, bounded by `Into<Ti>`, then re-split so the
+        // bounds land correctly on both the `impl<...>` and `where` positions.
+        let mut into_generics = generics.clone();
+        for param in &into_params {
+            into_generics.params.push(syn::parse_quote!(#param));
+        }
+        {
+            let into_where_clause = into_generics.make_where_clause();
+            for (param, ty) in into_params.iter().zip(tuple_tys.iter()) {
+                into_where_clause
+                    .predicates
+                    .push(syn::parse_quote! { #param: ::core::convert::Into<#ty> });
+            }
+        }
+        let (into_impl_generics, _, into_where_clause) = into_generics.split_for_impl();
+
+        quote! {
+            impl #into_impl_generics ::core::convert::From<#into_tuple_ty_ts> for #ident #ty_generics
+            #into_where_clause {
+                fn from(#pattern_ts: #into_tuple_ty_ts) -> Self {
+                    #into_construct_ts
+                }
+            }
+        }
+    } else {
+        let construct_ts = build_construct(&|dvar| quote! { #dvar });
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics ::core::convert::From<#tuple_ty_ts> for #ident #ty_generics
+            #where_clause {
+                fn from(#pattern_ts: #tuple_ty_ts) -> Self {
+                    #construct_ts
+                }
             }
         }
     };
-    ts.into()
+
+    (ts, tuple_tys)
+}
+
+/// Checks whether any of `attrs` is `#[from_tuple(#arg)]`, e.g. `from_tuple_attr_is(attrs, "into")`
+/// for a struct-level `#[from_tuple(into)]`, or `from_tuple_attr_is(attrs, "default")` for a
+/// field-level `#[from_tuple(default)]`.
+#[cfg(feature="order_dependent")]
+fn from_tuple_attr_is(attrs: &[syn::Attribute], arg: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path.is_ident("from_tuple")
+            && attr
+                .parse_args::<proc_macro2::Ident>()
+                .map(|ident| ident == arg)
+                .unwrap_or(false)
+    })
+}
+
+/// Checks for a struct-level `#[from_tuple(into)]` attribute, which switches
+/// [`derive_from`] into emitting a converting, `Into`-bounded generic `impl`
+/// instead of the exact-type one.
+#[cfg(feature="order_dependent")]
+fn from_tuple_into_attr(attrs: &[syn::Attribute]) -> bool {
+    from_tuple_attr_is(attrs, "into")
+}
+
+/// Checks for a field-level `#[from_tuple(default)]` attribute, which excludes
+/// the field from [`derive_from`]'s tuple and fills it with `Default::default()`.
+#[cfg(feature="order_dependent")]
+fn from_tuple_default_attr(attrs: &[syn::Attribute]) -> bool {
+    from_tuple_attr_is(attrs, "default")
+}
+
+/// Derives a fallible [`core::convert::TryFrom<(...)>`][core::convert::TryFrom] for
+/// `struct`s whose fields are built from tuple elements that convert via
+/// [`core::convert::TryInto`], rather than the infallible [`From`] the other two
+/// derives produce.
+///
+/// This is the derive to reach for when a field type can reject the incoming
+/// value, such as a `NonZeroU32` or a validated newtype.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::num::NonZeroU32;
+/// use from_tuple::TryFromTuple;
+///
+/// #[derive(TryFromTuple)]
+/// struct Hello {
+///     count: NonZeroU32,
+///     flag: bool,
+/// }
+///
+/// let h = Hello::try_from((3u32, true)).unwrap();
+/// assert_eq!(h.count.get(), 3);
+/// assert!(h.flag);
+///
+/// assert!(Hello::try_from((0u32, true)).is_err());
+/// ```
+///
+/// ## Single-field structs
+///
+/// A single-field struct still derives a `TryFrom` for a 1-tuple, not a bare
+/// value, matching [`FromStrictlyHeterogeneousTuple`] and [`OrderDependentFromTuple`].
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::num::NonZeroU32;
+/// use from_tuple::TryFromTuple;
+///
+/// #[derive(TryFromTuple)]
+/// struct SingleTry {
+///     val: NonZeroU32,
+/// }
+///
+/// let s = SingleTry::try_from((3u32,)).unwrap();
+/// assert_eq!(s.val.get(), 3);
+///
+/// assert!(SingleTry::try_from((0u32,)).is_err());
+/// ```
+///
+/// ## Generic structs
+///
+/// Like the other two derives, the struct's own generics and where-clause are
+/// threaded through into the generated `impl` and its companion `Error` type.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use from_tuple::TryFromTuple;
+///
+/// #[derive(TryFromTuple)]
+/// struct Wrapper<T> {
+///     val: T,
+/// }
+///
+/// let w: Wrapper<u8> = Wrapper::try_from((3u32,)).unwrap();
+/// assert_eq!(w.val, 3);
+/// ```
+///
+/// The generated `Error` type is named `#struct_identTryFromTupleError` and has one
+/// variant per field, wrapping that field's conversion's [`TryInto::Error`].
+#[cfg(feature = "try_from_tuple")]
+#[proc_macro_derive(TryFromTuple)]
+pub fn try_from_tuple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    if let Data::Struct(data) = &input.data {
+        impl_try_from_tuple(data, &input)
+    } else {
+        Error::new_spanned(&input, "TryFromTuple currently only supports Struct").to_compile_error()
+    }
+    .into()
 }
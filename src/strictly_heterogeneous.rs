@@ -1,42 +1,117 @@
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use std::collections::HashSet;
-use syn::{DeriveInput, Error, Field, Fields};
+use syn::{DeriveInput, Error, Field};
 
-/// `impl` `From` for a tuple of field types in the order of the fields passed
+/// Whether a field is annotated `#[from_tuple(default)]`, meaning it's filled in
+/// with `Default::default()` instead of being consumed from the input tuple.
+pub(super) fn is_defaulted(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        attr.path.is_ident("from_tuple")
+            && attr
+                .parse_args::<Ident>()
+                .map(|ident| ident == "default")
+                .unwrap_or(false)
+    })
+}
+
+/// `impl` `From` for a tuple of the non-defaulted field types, in the order of
+/// the fields passed.
 ///
 /// If the field types are `String`, `u8`, and `i32`, then the generated `impl`
 /// would be `impl From<(String, u8, i32)> for #struct` where `#struct` is the
 /// `struct` you are deriving on.
-pub(super) fn impl_from_tuple(fields: &[&Field], data: &DeriveInput) -> TokenStream2 {
+///
+/// `fields` (the non-defaulted, possibly permuted fields) and `defaulted` (the
+/// `#[from_tuple(default)]` fields, always filled via `Default::default()`)
+/// may come from a named, tuple, or unit struct (or enum variant). Named
+/// fields are assigned by name, tuple fields are reassembled positionally
+/// according to their original (pre-permutation) order, and no fields at all
+/// becomes `impl From<()>`.
+///
+/// `target` is the constructor path to build, `Self` for a plain struct or
+/// `Self::Variant` for one variant of an enum; `struct_ident` (taken from
+/// `data`, the top-level `struct`/`enum`) is always what the `impl` is `for`.
+pub(super) fn impl_from_tuple(
+    fields: &[(usize, &Field)],
+    defaulted: &[(usize, &Field)],
+    data: &DeriveInput,
+    target: &TokenStream2,
+) -> TokenStream2 {
     let struct_ident = &data.ident;
+    let (impl_generics, ty_generics, where_clause) = data.generics.split_for_impl();
     let dvars = (0..fields.len())
         .map(|i| Ident::new(&format!("d{}", i), Span::call_site()))
         .collect::<Vec<_>>();
 
-    let idents = fields.iter().map(|&f| f.ident.as_ref());
-    let types = fields.iter().map(|&f| &f.ty);
+    let types = fields.iter().map(|&(_, f)| &f.ty);
+
+    // With exactly one field, `(#(#types),*)` would expand to the parenthesized type
+    // `(T0)` rather than the 1-tuple `(T0,)` — keep the trailing comma so
+    // `tuple_type`/`destructed` are always real tuples.
+    let tuple_type = quote! { (#(#types,)*) };
+    let destructed = quote! { (#(#dvars,)*) };
+
+    let sample_field = fields.first().or_else(|| defaulted.first()).map(|&(_, f)| f);
 
-    let tuple_type = quote! { (#(#types),*) };
-    let destructed = quote! { (#(#dvars),*) };
+    let construct = match sample_field {
+        None => quote! { #target },
+        Some(field) if field.ident.is_some() => {
+            let from_tuple_assigns = fields.iter().zip(dvars.iter()).map(|(&(_, f), dvar)| {
+                let ident = f.ident.as_ref();
+                quote! { #ident: #dvar }
+            });
+            let defaulted_assigns = defaulted.iter().map(|&(_, f)| {
+                let ident = f.ident.as_ref();
+                quote! { #ident: ::core::default::Default::default() }
+            });
+            quote! { #target { #(#from_tuple_assigns,)* #(#defaulted_assigns),* } }
+        }
+        Some(_) => {
+            // Tuple struct: `fields` may be in a permuted order, so the `dvars`
+            // have to be put back into the struct's original field order,
+            // interleaved with the defaulted fields, before being passed
+            // positionally to `#target(...)`.
+            let mut by_original_order = fields
+                .iter()
+                .zip(dvars.iter())
+                .map(|(&(idx, _), dvar)| (idx, quote! { #dvar }))
+                .collect::<Vec<_>>();
+            by_original_order.extend(
+                defaulted
+                    .iter()
+                    .map(|&(idx, _)| (idx, quote! { ::core::default::Default::default() })),
+            );
+            by_original_order.sort_by_key(|&(idx, _)| idx);
+            let ordered = by_original_order.into_iter().map(|(_, expr)| expr);
+            quote! { #target(#(#ordered),*) }
+        }
+    };
 
     quote! {
-        impl From<#tuple_type> for #struct_ident {
+        impl #impl_generics From<#tuple_type> for #struct_ident #ty_generics #where_clause {
 
             #[inline]
             fn from(tuple: #tuple_type) -> Self {
                 let #destructed = tuple;
 
-                Self {
-                    #(#idents: #dvars),*
-                }
+                #construct
             }
         }
     }
 }
 
+/// The tuple of field types an `impl_from_tuple`-generated `impl` would consume, in
+/// the order `fields` is given. Used to detect two variants of an enum producing
+/// overlapping/coherence-conflicting impls for the exact same tuple type.
+pub(super) fn tuple_signature(fields: &[(usize, &Field)]) -> Vec<syn::Type> {
+    fields.iter().map(|&(_, f)| f.ty.clone()).collect()
+}
+
 /// Create spanned errors for every non-unique field type
-pub(super) fn verify_unique_field_types(fields: &syn::Fields) -> syn::Result<()> {
+pub(super) fn verify_unique_field_types<'a>(
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> syn::Result<()> {
     let mut seen = HashSet::new();
     let mut error = None;
 
@@ -60,17 +135,21 @@ pub(super) fn verify_unique_field_types(fields: &syn::Fields) -> syn::Result<()>
     }
 }
 
-/// Pass all permutations of `syn::Fields` to a callback
+/// Pass all permutations of a slice of `(original_index, Field)` pairs to a callback
+///
+/// Each field is paired with its original (pre-permutation) index so that
+/// callbacks which must reconstruct a field's position, such as tuple
+/// structs, can recover the struct's declared order.
 ///
 /// Uses an iterative version of [`Heap's Algorithm`] to efficiently generate
 /// all permutations.
 ///
 /// [`Heap's Algorithm`]: https://en.wikipedia.org/wiki/Heap%27s_algorithm
-pub(super) fn permute<F>(fields: &Fields, mut callback: F)
+pub(super) fn permute<F>(fields: &[(usize, &Field)], mut callback: F)
 where
-    F: FnMut(&[&Field]),
+    F: FnMut(&[(usize, &Field)]),
 {
-    let mut data = fields.iter().collect::<Vec<_>>();
+    let mut data = fields.to_vec();
 
     // the first permutation is just the unmodified field order
     callback(&data);
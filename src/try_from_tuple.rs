@@ -0,0 +1,151 @@
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{DataStruct, DeriveInput, Fields};
+
+/// `impl` a fallible `TryFrom` for a tuple whose elements convert into each field's
+/// type via [`TryInto`], alongside a companion `Error` enum with one variant per field.
+///
+/// If the field types are `NonZeroU32` and `u8`, the generated `impl` is roughly
+/// `impl<A0: TryInto<NonZeroU32>, A1: TryInto<u8>> TryFrom<(A0, A1)> for #struct`, with
+/// `type Error = #struct_TryFromTupleError<A0, A1>` carrying whichever field's
+/// conversion failed. The deriving struct's own generics and where-clause are threaded
+/// through both the `impl` and the error type, the same way `OrderDependentFromTuple`
+/// threads them for its `#[from_tuple(into)]` impl.
+pub(super) fn impl_try_from_tuple(data: &DataStruct, input: &DeriveInput) -> TokenStream2 {
+    let struct_ident = &input.ident;
+    let error_ident = Ident::new(
+        &format!("{}TryFromTupleError", struct_ident),
+        Span::call_site(),
+    );
+
+    let field_tys = data.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+    let dvars = (0..field_tys.len())
+        .map(|i| Ident::new(&format!("d{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let into_params = (0..field_tys.len())
+        .map(|i| Ident::new(&format!("A{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let variant_idents = (0..field_tys.len())
+        .map(|i| Ident::new(&format!("Field{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+
+    // With exactly one field, `(#(#into_params),*)` would expand to the parenthesized
+    // type `(A0)` rather than the 1-tuple `(A0,)` — keep the trailing comma so
+    // `tuple_type`/`destructed` are always real tuples.
+    let tuple_type = quote! { (#(#into_params,)*) };
+    let destructed = quote! { (#(#dvars,)*) };
+
+    let construct = match &data.fields {
+        Fields::Named(_) => {
+            let idents = data.fields.iter().map(|f| f.ident.as_ref());
+            quote! { Self { #(#idents: #dvars),* } }
+        }
+        Fields::Unnamed(_) => quote! { Self(#(#dvars),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let try_converts = dvars.iter().zip(variant_idents.iter()).map(|(dvar, variant)| {
+        quote! {
+            let #dvar = ::core::convert::TryInto::try_into(#dvar).map_err(#error_ident::#variant)?;
+        }
+    });
+
+    let error_variants = variant_idents
+        .iter()
+        .zip(into_params.iter())
+        .zip(field_tys.iter())
+        .map(|((variant, a), ty)| {
+            quote! { #variant(<#a as ::core::convert::TryInto<#ty>>::Error) }
+        });
+
+    let display_arms = variant_idents.iter().zip(field_tys.iter()).map(|(variant, ty)| {
+        quote! {
+            #error_ident::#variant(source) => ::core::write!(
+                f,
+                "failed to convert tuple element into field of type `{}`: {:?}",
+                stringify!(#ty),
+                source,
+            ),
+        }
+    });
+
+    // `#struct_ident`'s own generics, for referencing `#struct_ident #ty_generics` as
+    // the `TryFrom` impl target.
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // Clone the struct's own generics and extend them with one fresh type parameter
+    // per field, bounded by `TryInto<Ti>`, then re-split so the bounds land correctly
+    // on both the `impl<...>`/`enum ... <...>` and `where` positions. The error enum
+    // and its impls are generic over both the struct's own generics (since a field's
+    // type may reference them) and the `into_params`.
+    let mut try_into_generics = input.generics.clone();
+    for param in &into_params {
+        try_into_generics.params.push(syn::parse_quote!(#param));
+    }
+    {
+        let where_clause = try_into_generics.make_where_clause();
+        for (param, ty) in into_params.iter().zip(field_tys.iter()) {
+            where_clause
+                .predicates
+                .push(syn::parse_quote! { #param: ::core::convert::TryInto<#ty> });
+        }
+    }
+    let (try_into_impl_generics, error_ty_generics, try_into_where_clause) =
+        try_into_generics.split_for_impl();
+
+    // `Debug`/`Display` additionally need each field's conversion error to be `Debug`.
+    let mut debug_generics = try_into_generics.clone();
+    {
+        let where_clause = debug_generics.make_where_clause();
+        for (param, ty) in into_params.iter().zip(field_tys.iter()) {
+            where_clause.predicates.push(
+                syn::parse_quote! { <#param as ::core::convert::TryInto<#ty>>::Error: ::core::fmt::Debug },
+            );
+        }
+    }
+    let (debug_impl_generics, _, debug_where_clause) = debug_generics.split_for_impl();
+
+    quote! {
+        /// Error returned by the generated `TryFrom` impl when converting one of the
+        /// tuple's elements into its corresponding field fails.
+        pub enum #error_ident #try_into_impl_generics
+        #try_into_where_clause
+        {
+            #(#error_variants),*
+        }
+
+        impl #debug_impl_generics ::core::fmt::Debug for #error_ident #error_ty_generics
+        #debug_where_clause
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+
+        impl #debug_impl_generics ::core::fmt::Display for #error_ident #error_ty_generics
+        #debug_where_clause
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(self, f)
+            }
+        }
+
+        impl #try_into_impl_generics ::core::convert::TryFrom<#tuple_type> for #struct_ident #ty_generics
+        #try_into_where_clause
+        {
+            type Error = #error_ident #error_ty_generics;
+
+            #[inline]
+            fn try_from(tuple: #tuple_type) -> ::core::result::Result<Self, Self::Error> {
+                let #destructed = tuple;
+
+                #(#try_converts)*
+
+                ::core::result::Result::Ok(#construct)
+            }
+        }
+    }
+}